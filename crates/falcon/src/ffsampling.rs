@@ -2,13 +2,183 @@ use num_complex::{Complex, Complex64};
 use rand::RngCore;
 use rand_distr::num_traits::{One, Zero};
 
-use crate::{falcon, fast_fft::FastFft, polynomial::Polynomial, samplerz::sampler_z};
+use crate::{
+    falcon::{self, FalconError},
+    fast_fft::FastFft,
+    polynomial::Polynomial,
+    samplerz::sampler_z,
+};
+
+/// Validate that all four entries of a 2x2 polynomial matrix share a single
+/// non-empty length, returning that length. Used as the precondition check for
+/// the Hadamard routines so that malformed or reconstructed key material
+/// produces a clean [`FalconError`] instead of a panic.
+fn matrix_dimension(m: &[Polynomial<Complex64>; 4]) -> Result<usize, FalconError> {
+    let n = m[0].coefficients.len();
+    if n == 0 || m.iter().any(|p| p.coefficients.len() != n) {
+        return Err(FalconError::InvalidDimension(n));
+    }
+    Ok(n)
+}
+
+/// Arithmetic backend for the FFT/sampling path. Recorded on
+/// [`FalconParameters`] and propagated into the tree build so that the whole
+/// `gram` → `ldl` → `ffldl` → `ffsampling` pipeline is evaluated with one
+/// consistent arithmetic.
+///
+/// [`FalconParameters`]: crate::falcon::FalconParameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum Backend {
+    /// Hardware `f64` arithmetic: fastest, but not bit-reproducible across
+    /// platforms because of FMA contraction, x87 extended precision, and
+    /// reassociation.
+    #[default]
+    Float,
+    /// Software fixed-point arithmetic: fully deterministic, for KAT /
+    /// test-vector reproduction. Requires the `fixed-point` feature; without it
+    /// the routines fall back to [`Backend::Float`].
+    FixedPoint,
+}
+
+/// Whether `backend` should route arithmetic through the deterministic
+/// fixed-point representation. Always false unless the `fixed-point` feature is
+/// compiled in.
+#[inline]
+fn use_fixed(backend: Backend) -> bool {
+    #[cfg(feature = "fixed-point")]
+    {
+        matches!(backend, Backend::FixedPoint)
+    }
+    #[cfg(not(feature = "fixed-point"))]
+    {
+        let _ = backend;
+        false
+    }
+}
+
+#[cfg(feature = "fixed-point")]
+fn to_fixed(p: &Polynomial<Complex64>) -> Vec<crate::fixed::FixedComplex> {
+    use crate::fixed::{Fixed, FixedComplex};
+    p.coefficients
+        .iter()
+        .map(|c| FixedComplex::new(Fixed::from_f64(c.re), Fixed::from_f64(c.im)))
+        .collect()
+}
+
+/// Like [`to_fixed`] but zero-padded to `len`, so element-wise sums/differences
+/// of polynomials of unequal length match the padding behaviour of
+/// `Polynomial`'s own `+`/`-`.
+#[cfg(feature = "fixed-point")]
+fn to_fixed_padded(p: &Polynomial<Complex64>, len: usize) -> Vec<crate::fixed::FixedComplex> {
+    use crate::fixed::FixedComplex;
+    let mut v = to_fixed(p);
+    v.resize(len, FixedComplex::ZERO);
+    v
+}
+
+#[cfg(feature = "fixed-point")]
+fn from_fixed(v: &[crate::fixed::FixedComplex]) -> Polynomial<Complex64> {
+    Polynomial::new(
+        v.iter()
+            .map(|c| Complex64::new(c.re.to_f64(), c.im.to_f64()))
+            .collect(),
+    )
+}
+
+/// Element-wise product, evaluated in the backend's arithmetic.
+fn had_mul(
+    a: &Polynomial<Complex64>,
+    b: &Polynomial<Complex64>,
+    backend: Backend,
+) -> Polynomial<Complex64> {
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) {
+        return from_fixed(&crate::fixed::hadamard_mul(&to_fixed(a), &to_fixed(b)));
+    }
+    let _ = backend;
+    a.hadamard_mul(b)
+}
+
+/// Element-wise quotient `a / b`, evaluated in the backend's arithmetic.
+fn had_div(
+    a: &Polynomial<Complex64>,
+    b: &Polynomial<Complex64>,
+    backend: Backend,
+) -> Polynomial<Complex64> {
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) {
+        return from_fixed(&crate::fixed::hadamard_div(&to_fixed(a), &to_fixed(b)));
+    }
+    let _ = backend;
+    a.hadamard_div(b)
+}
+
+/// Element-wise sum, evaluated in the backend's arithmetic.
+fn poly_add(
+    a: &Polynomial<Complex64>,
+    b: &Polynomial<Complex64>,
+    backend: Backend,
+) -> Polynomial<Complex64> {
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) {
+        let len = a.coefficients.len().max(b.coefficients.len());
+        let (fa, fb) = (to_fixed_padded(a, len), to_fixed_padded(b, len));
+        return from_fixed(&fa.iter().zip(&fb).map(|(x, y)| x.add(*y)).collect::<Vec<_>>());
+    }
+    let _ = backend;
+    a.clone() + b.clone()
+}
+
+/// Element-wise difference `a - b`, evaluated in the backend's arithmetic.
+fn poly_sub(
+    a: &Polynomial<Complex64>,
+    b: &Polynomial<Complex64>,
+    backend: Backend,
+) -> Polynomial<Complex64> {
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) {
+        let len = a.coefficients.len().max(b.coefficients.len());
+        let (fa, fb) = (to_fixed_padded(a, len), to_fixed_padded(b, len));
+        return from_fixed(&fa.iter().zip(&fb).map(|(x, y)| x.sub(*y)).collect::<Vec<_>>());
+    }
+    let _ = backend;
+    a.clone() - b.clone()
+}
+
+/// FFT split, evaluated in the backend's arithmetic.
+fn split(a: &Polynomial<Complex64>, backend: Backend) -> (Polynomial<Complex64>, Polynomial<Complex64>) {
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) {
+        let (f0, f1) = crate::fixed::split_fft(&to_fixed(a));
+        return (from_fixed(&f0), from_fixed(&f1));
+    }
+    let _ = backend;
+    a.split_fft()
+}
+
+/// FFT merge, evaluated in the backend's arithmetic.
+fn merge(
+    a: &Polynomial<Complex64>,
+    b: &Polynomial<Complex64>,
+    backend: Backend,
+) -> Polynomial<Complex64> {
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) {
+        return from_fixed(&crate::fixed::merge_fft(&to_fixed(a), &to_fixed(b)));
+    }
+    let _ = backend;
+    Polynomial::<Complex64>::merge_fft(a, b)
+}
 
 /// Computes the Gram matrix. The argument must be a 2x2 matrix
 /// whose elements are equal-length vectors of complex numbers,
 /// representing polynomials in FFT domain.
-pub(crate) fn gram(b: [Polynomial<Complex64>; 4]) -> [Polynomial<Complex64>; 4] {
+pub(crate) fn gram(
+    b: [Polynomial<Complex64>; 4],
+    backend: Backend,
+) -> Result<[Polynomial<Complex64>; 4], FalconError> {
     const N: usize = 2;
+    matrix_dimension(&b)?;
     let mut g: [Polynomial<Complex<f64>>; 4] = [
         Polynomial::zero(),
         Polynomial::zero(),
@@ -18,12 +188,12 @@ pub(crate) fn gram(b: [Polynomial<Complex64>; 4]) -> [Polynomial<Complex64>; 4]
     for i in 0..N {
         for j in 0..N {
             for k in 0..N {
-                g[N * i + j] = g[N * i + j].clone()
-                    + b[N * i + k].hadamard_mul(&b[N * j + k].map(|c| c.conj()));
+                let term = had_mul(&b[N * i + k], &b[N * j + k].map(|c| c.conj()), backend);
+                g[N * i + j] = poly_add(&g[N * i + j], &term, backend);
             }
         }
     }
-    g
+    Ok(g)
 }
 
 /// Compute the LDL decomposition of a 2x2 matrix G such that
@@ -32,63 +202,248 @@ pub(crate) fn gram(b: [Polynomial<Complex64>; 4]) -> [Polynomial<Complex64>; 4]
 /// are in FFT domain.
 pub(crate) fn ldl(
     g: [Polynomial<Complex64>; 4],
-) -> ([Polynomial<Complex64>; 4], [Polynomial<Complex64>; 4]) {
+    backend: Backend,
+) -> Result<([Polynomial<Complex64>; 4], [Polynomial<Complex64>; 4]), FalconError> {
+    matrix_dimension(&g)?;
+    // A zero on the diagonal makes the Hadamard division degenerate; reject it
+    // rather than dividing by zero and propagating NaNs/inf through the tree.
+    if g[0].coefficients.iter().any(|c| c.re == 0.0 && c.im == 0.0) {
+        return Err(FalconError::DegenerateLdl);
+    }
+    // Under the fixed-point backend the divisor is quantized first, so a tiny
+    // nonzero coefficient can collapse to a zero squared magnitude and trigger
+    // an integer divide-by-zero inside `had_div`. Reject it using the exact
+    // quantized test the division will apply.
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) && crate::fixed::any_zero_norm(&to_fixed(&g[0])) {
+        return Err(FalconError::DegenerateLdl);
+    }
     let zero = Polynomial::<Complex64>::one();
     let one = Polynomial::<Complex64>::zero();
 
-    let l10 = g[2].hadamard_div(&g[0]);
-    let bc = l10.map(|c| c * c.conj());
-    let abc = g[0].hadamard_mul(&bc);
-    let d11 = g[3].clone() - abc;
+    let l10 = had_div(&g[2], &g[0], backend);
+    let bc = had_mul(&l10, &l10.map(|c| c.conj()), backend);
+    let abc = had_mul(&g[0], &bc, backend);
+    let d11 = poly_sub(&g[3], &abc, backend);
 
     let l = [one.clone(), zero.clone(), l10.clone(), one];
     let d = [g[0].clone(), zero.clone(), zero, d11];
-    (l, d)
+    Ok((l, d))
+}
+
+/// A flattened LDL tree laid out depth-first in a single contiguous buffer.
+///
+/// For a gram matrix whose polynomials have length `n == 2^logn`, the tree
+/// occupies exactly `(logn + 1) << logn` complex elements (see [`tree_size`]).
+/// A node stores its `L[1][0]` polynomial of size `n` first, immediately
+/// followed by its left then right subtrees (each built on polynomials of
+/// size `n / 2`). The leaves, reached when the subtree size drops to one, are
+/// the single diagonal values consumed by [`normalize_tree`] and
+/// [`ffsampling`]. This removes the per-node `Box` allocation of the previous
+/// enum representation, keeps the whole tree cache-local, and makes it
+/// trivially serializable.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct LdlTree {
+    /// Length of the polynomials stored at the root node; always a power of two.
+    pub(crate) n: usize,
+    /// Depth-first flat layout of the tree, `tree_size(n)` elements long.
+    pub(crate) data: Vec<Complex64>,
+}
+
+impl LdlTree {
+    /// Build the normalized LDL tree for a key basis in FFT form and the given
+    /// standard deviation `sigma`, running `gram` → `ffldl` → `normalize_tree`
+    /// once. The tree depends only on the secret basis, not on the message, so
+    /// the result can be cached on the expanded signing key and reused for many
+    /// signatures. See [`LdlTree::to_bytes`]/[`LdlTree::from_bytes`] for
+    /// persisting the expanded key.
+    pub(crate) fn normalized(
+        basis: [Polynomial<Complex64>; 4],
+        sigma: f64,
+        backend: Backend,
+    ) -> Result<LdlTree, FalconError> {
+        let g = gram(basis, backend)?;
+        let mut tree = ffldl(g, backend)?;
+        normalize_tree(&mut tree, sigma, backend)?;
+        Ok(tree)
+    }
+
+    /// Serialize the (normalized) tree to a byte string: the root length `n` as
+    /// a little-endian `u64`, followed by each complex element as two
+    /// little-endian `f64`s. Lets a high-throughput server store the expanded
+    /// key and load it without recomputing the FFT decomposition per signature.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + self.data.len() * 16);
+        bytes.extend_from_slice(&(self.n as u64).to_le_bytes());
+        for c in &self.data {
+            bytes.extend_from_slice(&c.re.to_le_bytes());
+            bytes.extend_from_slice(&c.im.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Reconstruct a tree from [`LdlTree::to_bytes`], validating that the
+    /// encoded dimensions are self-consistent (power-of-two root length and an
+    /// element count matching [`tree_size`]) before the tree is used in
+    /// signing.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<LdlTree, FalconError> {
+        if bytes.len() < 8 {
+            return Err(FalconError::InvalidDimension(bytes.len()));
+        }
+        let n = u64::from_le_bytes(bytes[..8].try_into().unwrap()) as usize;
+        if n == 0 || !n.is_power_of_two() {
+            return Err(FalconError::InvalidDimension(n));
+        }
+        let expected = tree_size(n);
+        let body = &bytes[8..];
+        if body.len() != expected * 16 {
+            return Err(FalconError::InvalidDimension(body.len()));
+        }
+        let mut data = Vec::with_capacity(expected);
+        for chunk in body.chunks_exact(16) {
+            let re = f64::from_le_bytes(chunk[..8].try_into().unwrap());
+            let im = f64::from_le_bytes(chunk[8..].try_into().unwrap());
+            data.push(Complex64::new(re, im));
+        }
+        Ok(LdlTree { n, data })
+    }
+}
+
+/// Root length at or above which [`ffldl_into`] splits its two child
+/// recursions across a `rayon::join`. Below this the fork-join overhead
+/// dominates the remaining work, so the construction stays serial. Only used
+/// by the `rayon` feature.
+#[cfg(feature = "rayon")]
+const PARALLEL_CUTOFF: usize = 64;
+
+/// Number of `Complex64` elements a flat LDL tree built on polynomials of
+/// length `n` occupies, i.e. `(log2(n) + 1) << log2(n)`.
+pub(crate) fn tree_size(n: usize) -> usize {
+    (n.ilog2() as usize + 1) << n.ilog2()
 }
 
-#[derive(Debug, Clone)]
-pub(crate) enum LdlTree {
-    Branch(Polynomial<Complex64>, Box<LdlTree>, Box<LdlTree>),
-    Leaf([Complex64; 2]),
+/// Offsets, relative to a node's own start, of its left and right subtrees.
+/// The node's `L[1][0]` polynomial occupies `0..n`; the left subtree follows,
+/// and the right subtree follows that.
+fn subtree_offsets(n: usize) -> (usize, usize) {
+    let child = tree_size(n / 2);
+    (n, n + child)
 }
 
-/// Compute the LDL Tree of G. Corresponds to Algorithm 9 of the
-/// specification [1, p.37]. The argument is a 2x2 matrix of
-/// polynomials, given in FFT form.
+/// Compute the LDL Tree of G and return it as a freshly allocated flat tree.
+/// Corresponds to Algorithm 9 of the specification [1, p.37]. The argument is
+/// a 2x2 matrix of polynomials, given in FFT form.
 ///
 /// [1]: https://falcon-sign.info/falcon.pdf
-pub(crate) fn ffldl(gram_matrix: [Polynomial<Complex64>; 4]) -> LdlTree {
-    let n = gram_matrix[0].coefficients.len();
-    let (l, d) = ldl(gram_matrix);
+pub(crate) fn ffldl(
+    gram_matrix: [Polynomial<Complex64>; 4],
+    backend: Backend,
+) -> Result<LdlTree, FalconError> {
+    let n = matrix_dimension(&gram_matrix)?;
+    let mut data = vec![Complex64::zero(); tree_size(n)];
+    ffldl_into(gram_matrix, &mut data, backend)?;
+    Ok(LdlTree { n, data })
+}
+
+/// Build the LDL tree into a caller-provided buffer, which must be exactly
+/// `tree_size(n)` elements long where `n` is the length of the gram
+/// polynomials. Used both by [`ffldl`] and when writing many subtrees into one
+/// shared arena. Returns an error for non-power-of-two or mismatched inputs,
+/// or an undersized output buffer, rather than panicking.
+pub(crate) fn ffldl_into(
+    gram_matrix: [Polynomial<Complex64>; 4],
+    out: &mut [Complex64],
+    backend: Backend,
+) -> Result<(), FalconError> {
+    let n = matrix_dimension(&gram_matrix)?;
+    if !n.is_power_of_two() {
+        return Err(FalconError::InvalidDimension(n));
+    }
+    if out.len() != tree_size(n) {
+        return Err(FalconError::InvalidDimension(out.len()));
+    }
+    let (l, d) = ldl(gram_matrix, backend)?;
+
+    out[..n].copy_from_slice(&l[2].coefficients);
 
     if n > 2 {
-        let (d00, d01) = d[0].split_fft();
-        let (d10, d11) = d[3].split_fft();
+        let (d00, d01) = split(&d[0], backend);
+        let (d10, d11) = split(&d[3], backend);
         let g0 = [d00.clone(), d01.clone(), d01.map(|c| c.conj()), d00];
         let g1 = [d10.clone(), d11.clone(), d11.map(|c| c.conj()), d10];
-        LdlTree::Branch(l[2].clone(), Box::new(ffldl(g0)), Box::new(ffldl(g1)))
+
+        let (_, right_off) = subtree_offsets(n);
+        let (left, right) = out[n..].split_at_mut(right_off - n);
+
+        // The two children are fully independent and write into disjoint
+        // slices, so they can be built with a fork-join. Below `PARALLEL_CUTOFF`
+        // the recursion's work no longer outweighs the `rayon::join` overhead,
+        // so stay serial. `ffsampling` is inherently sequential and untouched.
+        #[cfg(feature = "rayon")]
+        if n >= PARALLEL_CUTOFF {
+            let (r0, r1) = rayon::join(
+                || ffldl_into(g0, left, backend),
+                || ffldl_into(g1, right, backend),
+            );
+            r0?;
+            r1?;
+        } else {
+            ffldl_into(g0, left, backend)?;
+            ffldl_into(g1, right, backend)?;
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        {
+            ffldl_into(g0, left, backend)?;
+            ffldl_into(g1, right, backend)?;
+        }
     } else {
-        LdlTree::Branch(
-            l[2].clone(),
-            Box::new(LdlTree::Leaf(d[0].clone().coefficients.try_into().unwrap())),
-            Box::new(LdlTree::Leaf(d[3].clone().coefficients.try_into().unwrap())),
-        )
+        // Leaves: a single diagonal value each, all that `normalize_tree` and
+        // `ffsampling` consume.
+        out[2] = d[0].coefficients[0];
+        out[3] = d[3].coefficients[0];
     }
+    Ok(())
 }
 
-pub(crate) fn normalize_tree(tree: &mut LdlTree, sigma: f64) {
-    match tree {
-        LdlTree::Branch(_ell, left, right) => {
-            normalize_tree(left, sigma);
-            normalize_tree(right, sigma);
-        }
-        LdlTree::Leaf(vector) => {
-            vector[0] = Complex::new(sigma / vector[0].re.sqrt(), 0.0);
-            vector[1] = Complex64::zero();
-        }
+pub(crate) fn normalize_tree(
+    tree: &mut LdlTree,
+    sigma: f64,
+    backend: Backend,
+) -> Result<(), FalconError> {
+    if tree.n == 0 || !tree.n.is_power_of_two() || tree.data.len() != tree_size(tree.n) {
+        return Err(FalconError::InvalidDimension(tree.data.len()));
+    }
+    normalize_flat(&mut tree.data, tree.n, sigma, backend);
+    Ok(())
+}
+
+fn normalize_flat(buf: &mut [Complex64], n: usize, sigma: f64, backend: Backend) {
+    if n > 2 {
+        let (_, right_off) = subtree_offsets(n);
+        let (left, right) = buf[n..].split_at_mut(right_off - n);
+        normalize_flat(left, n / 2, sigma, backend);
+        normalize_flat(right, n / 2, sigma, backend);
+    } else {
+        buf[2] = Complex::new(normalize_leaf(buf[2].re, sigma, backend), 0.0);
+        buf[3] = Complex::new(normalize_leaf(buf[3].re, sigma, backend), 0.0);
     }
 }
 
+/// Compute a normalized leaf value `sigma / sqrt(x)`, in the backend's
+/// arithmetic. Under [`Backend::FixedPoint`] the square root and division are
+/// evaluated in fixed point so no hardware `sqrt` enters the pipeline.
+#[inline]
+fn normalize_leaf(x: f64, sigma: f64, backend: Backend) -> f64 {
+    #[cfg(feature = "fixed-point")]
+    if use_fixed(backend) {
+        use crate::fixed::Fixed;
+        return (Fixed::from_f64(sigma) / Fixed::from_f64(x).sqrt()).to_f64();
+    }
+    let _ = backend;
+    sigma / x.sqrt()
+}
+
 /// Sample short polynomials using a Falcon tree. Algorithm 11 from the spec [1, p.40].
 ///
 /// [1]: https://falcon-sign.info/falcon.pdf
@@ -97,30 +452,64 @@ pub(crate) fn ffsampling(
     tree: &LdlTree,
     parameters: &falcon::FalconParameters,
     rng: &mut dyn RngCore,
+) -> Result<(Polynomial<Complex64>, Polynomial<Complex64>), FalconError> {
+    if tree.n == 0 || !tree.n.is_power_of_two() || tree.data.len() != tree_size(tree.n) {
+        return Err(FalconError::InvalidDimension(tree.data.len()));
+    }
+    if t.0.coefficients.len() != tree.n || t.1.coefficients.len() != tree.n {
+        return Err(FalconError::InvalidDimension(t.0.coefficients.len()));
+    }
+    Ok(ffsampling_flat(t, &tree.data, tree.n, parameters, rng))
+}
+
+fn ffsampling_flat(
+    t: &(Polynomial<Complex64>, Polynomial<Complex64>),
+    buf: &[Complex64],
+    n: usize,
+    parameters: &falcon::FalconParameters,
+    rng: &mut dyn RngCore,
 ) -> (Polynomial<Complex64>, Polynomial<Complex64>) {
-    match tree {
-        LdlTree::Branch(ell, left, right) => {
-            let bold_t1 = t.1.split_fft();
-            let bold_z1 = ffsampling(&bold_t1, right, parameters, rng);
-            let z1 = Polynomial::<Complex64>::merge_fft(&bold_z1.0, &bold_z1.1);
+    let backend = parameters.backend;
+    let ell = Polynomial::new(buf[..n].to_vec());
+    let child_len = n / 2;
+    let (left_off, right_off) = subtree_offsets(n);
+    let left = &buf[left_off..right_off];
+    let right = &buf[right_off..];
 
-            // t0' = t0  + (t1 - z1) * l
-            let t0_prime = t.0.clone() + (t.1.clone() - z1.clone()).hadamard_mul(ell);
+    let bold_t1 = split(&t.1, backend);
+    let bold_z1 = ffsampling_child(&bold_t1, right, child_len, parameters, rng);
+    let z1 = merge(&bold_z1.0, &bold_z1.1, backend);
 
-            let bold_t0 = t0_prime.split_fft();
-            let bold_z0 = ffsampling(&bold_t0, left, parameters, rng);
-            let z0 = Polynomial::<Complex64>::merge_fft(&bold_z0.0, &bold_z0.1);
+    // t0' = t0  + (t1 - z1) * l
+    let t0_prime = poly_add(&t.0, &had_mul(&poly_sub(&t.1, &z1, backend), &ell, backend), backend);
 
-            (z0, z1)
-        }
-        LdlTree::Leaf(value) => {
-            let z0 = sampler_z(t.0.coefficients[0].re, value[0].re, parameters.sigmin, rng);
-            let z1 = sampler_z(t.1.coefficients[0].re, value[0].re, parameters.sigmin, rng);
-            (
-                Polynomial::new(vec![Complex64::new(z0 as f64, 0.0)]),
-                Polynomial::new(vec![Complex64::new(z1 as f64, 0.0)]),
-            )
-        }
+    let bold_t0 = split(&t0_prime, backend);
+    let bold_z0 = ffsampling_child(&bold_t0, left, child_len, parameters, rng);
+    let z0 = merge(&bold_z0.0, &bold_z0.1, backend);
+
+    (z0, z1)
+}
+
+fn ffsampling_child(
+    t: &(Polynomial<Complex64>, Polynomial<Complex64>),
+    buf: &[Complex64],
+    n: usize,
+    parameters: &falcon::FalconParameters,
+    rng: &mut dyn RngCore,
+) -> (Polynomial<Complex64>, Polynomial<Complex64>) {
+    if n == 1 {
+        // The leaf's diagonal value and the targets were all produced by the
+        // backend's arithmetic upstream, so the centers handed to the sampler
+        // are already deterministic under `Backend::FixedPoint`.
+        let value = buf[0];
+        let z0 = sampler_z(t.0.coefficients[0].re, value.re, parameters.sigmin, rng);
+        let z1 = sampler_z(t.1.coefficients[0].re, value.re, parameters.sigmin, rng);
+        (
+            Polynomial::new(vec![Complex64::new(z0 as f64, 0.0)]),
+            Polynomial::new(vec![Complex64::new(z1 as f64, 0.0)]),
+        )
+    } else {
+        ffsampling_flat(t, buf, n, parameters, rng)
     }
 }
 
@@ -131,7 +520,10 @@ mod test {
     use rand::{thread_rng, Rng};
     use rand_distr::num_traits::Zero;
 
-    use crate::{ffsampling::gram, polynomial::Polynomial};
+    use crate::{
+        ffsampling::{ffldl, gram, ldl, Backend, LdlTree},
+        polynomial::Polynomial,
+    };
 
     #[test]
     fn test_gram() {
@@ -167,8 +559,174 @@ mod test {
             }
         }
 
-        let g = gram(a);
+        let g = gram(a, Backend::Float).unwrap();
 
         assert_eq!(c, g);
     }
+
+    #[test]
+    fn test_mismatched_matrix_is_error() {
+        // A 2x2 matrix whose entries have differing lengths must be rejected
+        // rather than triggering a length-assumption panic.
+        let m: [Polynomial<Complex64>; 4] = [
+            Polynomial::new(vec![Complex64::zero(); 4]),
+            Polynomial::new(vec![Complex64::zero(); 4]),
+            Polynomial::new(vec![Complex64::zero(); 3]),
+            Polynomial::new(vec![Complex64::zero(); 4]),
+        ];
+        assert!(gram(m.clone(), Backend::Float).is_err());
+        assert!(ldl(m, Backend::Float).is_err());
+    }
+
+    #[test]
+    fn test_odd_length_tree_is_error() {
+        // ffldl needs power-of-two lengths for the FFT split; an odd length
+        // must surface an error instead of panicking in the recursion.
+        let m: [Polynomial<Complex64>; 4] =
+            std::array::from_fn(|_| Polynomial::new(vec![Complex64::new(1.0, 0.0); 3]));
+        assert!(ffldl(m, Backend::Float).is_err());
+    }
+
+    #[test]
+    fn test_tree_serialization_round_trip() {
+        // Build a tree from a well-conditioned 4-coefficient basis, serialize
+        // it, and confirm the reconstruction is identical.
+        let basis: [Polynomial<Complex64>; 4] =
+            std::array::from_fn(|i| Polynomial::new(vec![Complex64::new((i + 2) as f64, 0.0); 4]));
+        let tree = ffldl(gram(basis, Backend::Float).unwrap(), Backend::Float).unwrap();
+        let bytes = tree.to_bytes();
+        let restored = LdlTree::from_bytes(&bytes).unwrap();
+        assert_eq!(tree, restored);
+    }
+
+    #[test]
+    fn test_tree_deserialization_rejects_bad_dimensions() {
+        // A truncated body must be rejected rather than yielding a mis-sized
+        // tree that would panic later in signing.
+        let basis: [Polynomial<Complex64>; 4] =
+            std::array::from_fn(|i| Polynomial::new(vec![Complex64::new((i + 2) as f64, 0.0); 4]));
+        let tree = ffldl(gram(basis, Backend::Float).unwrap(), Backend::Float).unwrap();
+        let mut bytes = tree.to_bytes();
+        bytes.truncate(bytes.len() - 16);
+        assert!(LdlTree::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_degenerate_ldl_is_error() {
+        // A zero on the g[0] diagonal makes the LDL division degenerate.
+        let m: [Polynomial<Complex64>; 4] =
+            std::array::from_fn(|_| Polynomial::new(vec![Complex64::zero(); 2]));
+        assert!(ldl(m, Backend::Float).is_err());
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn test_ldl_rejects_quantized_zero_divisor() {
+        // A g[0] that is a tiny nonzero f64 survives the exact-zero check but
+        // quantizes to zero under the fixed-point backend, which would be an
+        // integer divide-by-zero in `had_div`. It must be rejected instead.
+        let tiny = Complex64::new(1e-20, 0.0);
+        let m: [Polynomial<Complex64>; 4] = std::array::from_fn(|_| Polynomial::new(vec![tiny; 2]));
+        assert!(ldl(m.clone(), Backend::FixedPoint).is_err());
+        // The same matrix is finite (if ill-conditioned) under the float
+        // backend, so the stricter rejection is specific to fixed point.
+        assert!(ldl(m, Backend::Float).is_ok());
+    }
+
+    #[test]
+    fn test_normalized_tree_survives_reexpansion_and_reload() {
+        // A signing key caches this normalized tree at expansion time and reuses
+        // it for every signature. Reloading the serialized tree must yield the
+        // same tree a fresh expansion would, so a server can skip the FFT
+        // decomposition per signature.
+        let basis: [Polynomial<Complex64>; 4] =
+            std::array::from_fn(|i| Polynomial::new(vec![Complex64::new((i + 2) as f64, 0.0); 4]));
+        let cached = LdlTree::normalized(basis.clone(), 1.5, Backend::Float).unwrap();
+        let reloaded = LdlTree::from_bytes(&cached.to_bytes()).unwrap();
+        assert_eq!(cached, reloaded);
+
+        let reexpanded = LdlTree::normalized(basis, 1.5, Backend::Float).unwrap();
+        assert_eq!(cached, reexpanded);
+    }
+
+    #[cfg(feature = "fixed-point")]
+    #[test]
+    fn test_fixed_point_tree_matches_committed_vector() {
+        // The fixed-point backend must evaluate the whole gram/ffldl/normalize
+        // pipeline without hardware-float dependence. Comparing two builds on
+        // the same host only proves the code is deterministic on one libm; to
+        // catch cross-platform drift the tree is pinned to a hand-derived
+        // expected vector.
+        //
+        // The basis is chosen so every intermediate is an exact dyadic value
+        // (the twiddle constants themselves are pinned separately in
+        // `crate::fixed`), leaving the tree exactly representable in `f64`:
+        //
+        //   g = [[4,4,4,4],     _, [2,2,2,2], [2,2,2,2]]
+        //   L[1][0] = g[2]/g[0] = 0.5  throughout
+        //   left leaves  = sigma/sqrt(4) = 1.5/2 = 0.75
+        //   right leaves = sigma/sqrt(1) = 1.5/1 = 1.5
+        let col = |v: f64| Polynomial::new(vec![Complex64::new(v, 0.0); 4]);
+        let basis = [col(2.0), col(0.0), col(1.0), col(1.0)];
+        let tree = LdlTree::normalized(basis, 1.5, Backend::FixedPoint).unwrap();
+
+        let expect = |v: f64| Complex64::new(v, 0.0);
+        let expected = vec![
+            // root L[1][0] row
+            expect(0.5), expect(0.5), expect(0.5), expect(0.5),
+            // left subtree: L[1][0]=0, two normalized leaves
+            expect(0.0), expect(0.0), expect(0.75), expect(0.75),
+            // right subtree: L[1][0]=0, two normalized leaves
+            expect(0.0), expect(0.0), expect(1.5), expect(1.5),
+        ];
+        assert_eq!(tree.data, expected);
+    }
+
+    /// Serial reference build, mirroring `ffldl_into` but never forking, used
+    /// to pin the parallel layout to the serial one.
+    #[cfg(feature = "rayon")]
+    fn ffldl_into_serial(gram_matrix: [Polynomial<Complex64>; 4], out: &mut [Complex64]) {
+        use crate::ffsampling::{ldl, subtree_offsets, Backend};
+        let n = gram_matrix[0].coefficients.len();
+        let (l, d) = ldl(gram_matrix, Backend::Float).unwrap();
+        out[..n].copy_from_slice(&l[2].coefficients);
+        if n > 2 {
+            let (d00, d01) = d[0].split_fft();
+            let (d10, d11) = d[3].split_fft();
+            let g0 = [d00.clone(), d01.clone(), d01.map(|c| c.conj()), d00];
+            let g1 = [d10.clone(), d11.clone(), d11.map(|c| c.conj()), d10];
+            let (_, right_off) = subtree_offsets(n);
+            let (left, right) = out[n..].split_at_mut(right_off - n);
+            ffldl_into_serial(g0, left);
+            ffldl_into_serial(g1, right);
+        } else {
+            out[2] = d[0].coefficients[0];
+            out[3] = d[3].coefficients[0];
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_tree_matches_serial() {
+        use crate::ffsampling::{gram, tree_size, LdlTree};
+        // A Gram matrix of a nonsingular basis (determinant -2 at every
+        // coefficient) large enough to exercise the parallel cutoff.
+        let n = 128;
+        let basis: [Polynomial<Complex64>; 4] = std::array::from_fn(|i| {
+            Polynomial::new(
+                (0..n)
+                    .map(|k| Complex64::new((i + 2) as f64 + k as f64 * 0.01, 0.0))
+                    .collect_vec(),
+            )
+        });
+        let g = gram(basis, Backend::Float).unwrap();
+        let parallel = ffldl(g.clone(), Backend::Float).unwrap();
+        let mut serial_data = vec![Complex64::zero(); tree_size(n)];
+        ffldl_into_serial(g, &mut serial_data);
+        let serial = LdlTree {
+            n,
+            data: serial_data,
+        };
+        assert_eq!(parallel, serial);
+    }
 }
@@ -0,0 +1,359 @@
+//! Deterministic, hardware-float-free arithmetic backend for the FFT/sampling
+//! path.
+//!
+//! The default backend operates on [`num_complex::Complex64`] (`f64`), so the
+//! exact short vector produced while signing can differ between platforms and
+//! compilers: FMA contraction, x87 extended precision, and reassociation all
+//! perturb the low bits. That makes reproducing KAT/test vectors impossible.
+//!
+//! This module mirrors the reference design with a software-emulated
+//! fixed-precision complex type ([`Fixed`]/[`FixedComplex`]) whose rounding
+//! mode and operation order are fixed. The FFT/LDL routines in
+//! [`crate::ffsampling`] run `gram` → `ldl` → `ffldl` → `ffsampling` through
+//! these operations when the `Backend::FixedPoint` arithmetic is selected, so
+//! the pipeline evaluates identically on every platform. It is compiled only
+//! under the `fixed-point` feature.
+
+use core::ops::{Add, Div, Mul, Sub};
+
+/// Number of fractional bits kept by the fixed-point representation. 43 bits of
+/// fraction leave 20 integer bits in an `i64`, which comfortably covers the
+/// dynamic range of the gram/LDL recursion for all supported Falcon degrees.
+pub(crate) const FRAC_BITS: u32 = 43;
+
+const ONE: i64 = 1 << FRAC_BITS;
+
+/// A signed fixed-point real number with [`FRAC_BITS`] fractional bits, stored
+/// as the integer `round(x * 2^FRAC_BITS)`. Multiplication, division and
+/// conversion from `f64` round to nearest, ties away from zero, and
+/// [`Fixed::sqrt`] rounds down; each takes a single explicit rounding step — no
+/// hidden extended precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Fixed(pub i64);
+
+impl Fixed {
+    pub(crate) const ZERO: Fixed = Fixed(0);
+    pub(crate) const ONE: Fixed = Fixed(ONE);
+
+    /// Convert from an `f64`: an exact scaling by `2^FRAC_BITS` followed by one
+    /// rounding. Called each time `crate::ffsampling` hands an `f64` polynomial
+    /// or value to this backend, including during sampling.
+    pub(crate) fn from_f64(x: f64) -> Fixed {
+        Fixed((x * ONE as f64).round() as i64)
+    }
+
+    /// Exact representation of a (small) integer. Used to build the twiddle
+    /// recurrence and the `2` divisor entirely from integer constants, so no
+    /// `f64` ever enters the FFT butterfly path.
+    pub(crate) const fn from_i64(x: i64) -> Fixed {
+        Fixed(x << FRAC_BITS)
+    }
+
+    /// Convert back to `f64`. Called each time a fixed-point result is handed
+    /// back to `crate::ffsampling`, which includes the normalized leaf values
+    /// and the centers handed to the sampler.
+    pub(crate) fn to_f64(self) -> f64 {
+        self.0 as f64 / ONE as f64
+    }
+
+    /// Deterministic non-negative square root, computed entirely in integer
+    /// arithmetic so the result carries no hardware-float dependence. Used by
+    /// `normalize_tree` to turn a leaf's diagonal into `sigma / sqrt(·)`.
+    pub(crate) fn sqrt(self) -> Fixed {
+        debug_assert!(self.0 >= 0, "sqrt of negative fixed-point value");
+        // sqrt(v / 2^F) * 2^F == sqrt(v * 2^F); compute the integer sqrt of the
+        // up-shifted magnitude.
+        Fixed(isqrt_i128((self.0 as i128) << FRAC_BITS) as i64)
+    }
+
+    /// Round-to-nearest, ties away from zero, of a 128-bit product/quotient
+    /// already scaled so that `FRAC_BITS` fractional bits remain.
+    fn round_i128(value: i128, shift: u32) -> Fixed {
+        let half = 1i128 << (shift - 1);
+        let rounded = if value >= 0 {
+            (value + half) >> shift
+        } else {
+            -(((-value) + half) >> shift)
+        };
+        Fixed(rounded as i64)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+    fn add(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, rhs: Fixed) -> Fixed {
+        Fixed::round_i128(self.0 as i128 * rhs.0 as i128, FRAC_BITS)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+    fn div(self, rhs: Fixed) -> Fixed {
+        // (self << FRAC_BITS) / rhs, rounded — shift numerator up so the
+        // quotient retains FRAC_BITS fractional bits.
+        let num = (self.0 as i128) << FRAC_BITS;
+        let q = num / rhs.0 as i128;
+        let r = num % rhs.0 as i128;
+        // Round to nearest on the remainder, ties away from zero.
+        let adj = if r.abs() * 2 >= rhs.0.unsigned_abs() as i128 {
+            if (r >= 0) == (rhs.0 >= 0) {
+                1
+            } else {
+                -1
+            }
+        } else {
+            0
+        };
+        Fixed((q + adj) as i64)
+    }
+}
+
+/// A complex number built from two [`Fixed`] components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct FixedComplex {
+    pub re: Fixed,
+    pub im: Fixed,
+}
+
+impl FixedComplex {
+    pub(crate) const ZERO: FixedComplex = FixedComplex {
+        re: Fixed::ZERO,
+        im: Fixed::ZERO,
+    };
+
+    pub(crate) fn new(re: Fixed, im: Fixed) -> FixedComplex {
+        FixedComplex { re, im }
+    }
+
+    pub(crate) fn conj(self) -> FixedComplex {
+        FixedComplex {
+            re: self.re,
+            im: Fixed(-self.im.0),
+        }
+    }
+
+    /// Deterministic complex multiply with a fixed sub-product order.
+    pub(crate) fn mul(self, rhs: FixedComplex) -> FixedComplex {
+        let re = self.re * rhs.re - self.im * rhs.im;
+        let im = self.re * rhs.im + self.im * rhs.re;
+        FixedComplex { re, im }
+    }
+
+    /// Deterministic complex divide, `self / rhs`, evaluated as
+    /// `self * conj(rhs) / |rhs|^2` with a fixed operation order.
+    pub(crate) fn div(self, rhs: FixedComplex) -> FixedComplex {
+        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
+        let num = self.mul(rhs.conj());
+        FixedComplex {
+            re: num.re / denom,
+            im: num.im / denom,
+        }
+    }
+
+    pub(crate) fn add(self, rhs: FixedComplex) -> FixedComplex {
+        FixedComplex {
+            re: self.re + rhs.re,
+            im: self.im + rhs.im,
+        }
+    }
+
+    pub(crate) fn sub(self, rhs: FixedComplex) -> FixedComplex {
+        FixedComplex {
+            re: self.re - rhs.re,
+            im: self.im - rhs.im,
+        }
+    }
+}
+
+/// Element-wise (Hadamard) product of two equal-length vectors in the
+/// deterministic representation.
+pub(crate) fn hadamard_mul(a: &[FixedComplex], b: &[FixedComplex]) -> Vec<FixedComplex> {
+    a.iter().zip(b).map(|(x, y)| x.mul(*y)).collect()
+}
+
+/// Element-wise (Hadamard) quotient `a / b`.
+pub(crate) fn hadamard_div(a: &[FixedComplex], b: &[FixedComplex]) -> Vec<FixedComplex> {
+    a.iter().zip(b).map(|(x, y)| x.div(*y)).collect()
+}
+
+/// Whether any divisor in `b` has a squared magnitude that rounds to exactly
+/// zero in the fixed-point representation. [`FixedComplex::div`] divides by that
+/// same `re*re + im*im`, so a zero here would be an integer divide-by-zero; the
+/// caller rejects such a matrix as degenerate rather than letting it panic. A
+/// component that is a nonzero `f64` but quantizes below the representation's
+/// resolution lands here, which the raw `f64`-against-`0.0` test would miss.
+pub(crate) fn any_zero_norm(b: &[FixedComplex]) -> bool {
+    b.iter().any(|c| c.re * c.re + c.im * c.im == Fixed::ZERO)
+}
+
+/// Split an FFT-domain vector into its even/odd halves, mirroring
+/// `Polynomial::split_fft` but over [`FixedComplex`] and using the
+/// integer-only twiddles from [`twiddle`].
+pub(crate) fn split_fft(f: &[FixedComplex]) -> (Vec<FixedComplex>, Vec<FixedComplex>) {
+    let n = f.len();
+    let half = n / 2;
+    let mut f0 = Vec::with_capacity(half);
+    let mut f1 = Vec::with_capacity(half);
+    let two = Fixed::from_i64(2);
+    for i in 0..half {
+        let a = f[2 * i];
+        let b = f[2 * i + 1];
+        let sum = a.add(b);
+        f0.push(FixedComplex::new(sum.re / two, sum.im / two));
+        let diff = a.sub(b);
+        let w = twiddle(n, i).conj();
+        let t = diff.mul(w);
+        f1.push(FixedComplex::new(t.re / two, t.im / two));
+    }
+    (f0, f1)
+}
+
+/// Merge even/odd halves back into a full FFT-domain vector, the inverse of
+/// [`split_fft`].
+pub(crate) fn merge_fft(f0: &[FixedComplex], f1: &[FixedComplex]) -> Vec<FixedComplex> {
+    let half = f0.len();
+    let n = half * 2;
+    let mut f = vec![FixedComplex::ZERO; n];
+    for i in 0..half {
+        let w = twiddle(n, i);
+        let t = f1[i].mul(w);
+        f[2 * i] = f0[i].add(t);
+        f[2 * i + 1] = f0[i].sub(t);
+    }
+    f
+}
+
+/// Deterministic FFT twiddle factor `exp(i * pi * (2*rev(i)+1) / n)` for a
+/// transform of length `n`.
+///
+/// No trigonometric library call enters this path: the primitive root
+/// `exp(i * pi / n)` is built from `exp(i * pi) = -1` by repeated half-angle
+/// steps ([`root_pi_over`]) using only [`Fixed`] add/sub/div and the integer
+/// [`Fixed::sqrt`], and the required odd power is taken by square-and-multiply.
+/// Every step is exact integer arithmetic with one fixed rounding mode, so the
+/// constant is bit-identical on every platform — unlike a runtime `cos`/`sin`,
+/// whose last bits vary with the host libm.
+fn twiddle(n: usize, i: usize) -> FixedComplex {
+    let root = root_pi_over(n);
+    fixed_pow(root, 2 * bit_reverse(i, n / 2) as u64 + 1)
+}
+
+/// The primitive root `exp(i * pi / n)` for a power-of-two `n`, generated by the
+/// half-angle recurrence
+/// `cos(t/2) = sqrt((1 + cos t) / 2)`, `sin(t/2) = sqrt((1 - cos t) / 2)`
+/// seeded with `exp(i * pi) = (-1, 0)`. For `n >= 2` the angle `pi / n` lies in
+/// `(0, pi/2]`, so both components stay non-negative and the square roots are
+/// real.
+fn root_pi_over(n: usize) -> FixedComplex {
+    let two = Fixed::from_i64(2);
+    // Seed with the angle `pi` itself: exp(i * pi) = (-1, 0).
+    let mut c = Fixed::from_i64(-1);
+    let mut s = Fixed::ZERO;
+    for _ in 0..n.ilog2() {
+        // Both half-angle components derive from the *current* cosine.
+        let c_half = ((Fixed::ONE + c) / two).sqrt();
+        let s_half = ((Fixed::ONE - c) / two).sqrt();
+        c = c_half;
+        s = s_half;
+    }
+    FixedComplex::new(c, s)
+}
+
+/// Raise a [`FixedComplex`] to a non-negative integer power by
+/// square-and-multiply, keeping the operation order fixed.
+fn fixed_pow(base: FixedComplex, mut exp: u64) -> FixedComplex {
+    let mut acc = FixedComplex::new(Fixed::ONE, Fixed::ZERO);
+    let mut b = base;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc.mul(b);
+        }
+        b = b.mul(b);
+        exp >>= 1;
+    }
+    acc
+}
+
+fn bit_reverse(mut i: usize, n: usize) -> usize {
+    let bits = n.ilog2();
+    let mut r = 0;
+    for _ in 0..bits {
+        r = (r << 1) | (i & 1);
+        i >>= 1;
+    }
+    r
+}
+
+/// Integer square root (floor) of a non-negative 128-bit value, by bit-by-bit
+/// restoring. Kept in integer arithmetic so [`Fixed::sqrt`] stays deterministic.
+fn isqrt_i128(value: i128) -> i128 {
+    debug_assert!(value >= 0);
+    if value < 2 {
+        return value;
+    }
+    let mut bit = 1i128 << ((127 - value.leading_zeros()) & !1);
+    let mut root = 0i128;
+    let mut rem = value;
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    root
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn twiddle_roots_are_pinned_constants() {
+        // The twiddle recurrence is pure integer arithmetic, so these roots are
+        // exact and identical on every platform. exp(i*pi) and exp(i*pi/2) land
+        // on representable values and are pinned bit-for-bit; a regression to a
+        // libm `cos`/`sin` would perturb their low bits and fail here.
+        assert_eq!(
+            root_pi_over(1),
+            FixedComplex::new(Fixed::from_i64(-1), Fixed::ZERO)
+        );
+        assert_eq!(
+            root_pi_over(2),
+            FixedComplex::new(Fixed::ZERO, Fixed::ONE)
+        );
+        // exp(i*pi/4) is symmetric across the diagonal by construction.
+        let r4 = root_pi_over(4);
+        assert_eq!(r4.re, r4.im);
+        // Squaring exp(i*pi/4) returns exp(i*pi/2) to within a couple of ulps.
+        let sq = r4.mul(r4);
+        assert!(sq.re.0.abs() <= 2);
+        assert!((sq.im.0 - Fixed::ONE.0).abs() <= 2);
+    }
+
+    #[test]
+    fn fixed_mul_div_round_trip() {
+        let a = FixedComplex::new(Fixed::from_f64(1.5), Fixed::from_f64(-0.25));
+        let b = FixedComplex::new(Fixed::from_f64(0.75), Fixed::from_f64(2.0));
+        // (a * b) / b recovers a to within two ulps of the fraction.
+        let recovered = a.mul(b).div(b);
+        assert!((recovered.re.0 - a.re.0).abs() <= 2);
+        assert!((recovered.im.0 - a.im.0).abs() <= 2);
+    }
+}